@@ -55,6 +55,7 @@
 //! ```
 
 use std::{
+    cell::{Cell, Ref, RefCell, RefMut},
     fmt,
     marker::PhantomData,
     thread::{AccessError, LocalKey},
@@ -103,23 +104,55 @@ impl<'a> LocalScope<'a> {
     }
 
     /// Equivalent to [`LocalKey::try_with`] without the need for the closure.
-    pub fn try_access<T>(self, target: &'static LocalKey<T>) -> Result<&'a T, AccessError> {
-        target.try_with(
+    pub fn try_access<T>(self, target: impl LocalAccess<T>) -> Result<&'a T, AccessError> {
+        // safety: `LocalAccess`'s contract guarantees the returned reference is backed by
+        // storage that lives at least as long as the current thread's TLS region; by the
+        // condition on Self, that means it's valid for 'a too.
+        unsafe { target.try_access_raw() }.map(
             #[inline]
-            |tls| {
-                // safety: tls is a reference to data that lives in a TLS. by the condition on Self, this reference must actually live for 'a
-                unsafe { &*(tls as *const T) }
-            },
+            |tls| unsafe { &*(tls as *const T) },
         )
     }
 
     /// Equivalent to [`LocalKey::with`] without the need for the closure.
-    pub fn access<T>(self, target: &'static LocalKey<T>) -> &'a T {
+    pub fn access<T>(self, target: impl LocalAccess<T>) -> &'a T {
         match self.try_access(target) {
             Ok(x) => x,
             Err(ae) => panic_access_error(ae),
         }
     }
+
+    /// Reports whether `target`'s value is uninitialized, valid, or destroyed on this thread.
+    ///
+    /// For the bundled `&'static LocalKey<T>` backend this provides **no construction-avoidance
+    /// at all before first touch**: stable `std::thread::LocalKey` has no public way to tell
+    /// whether its value has been constructed without also constructing it, so
+    /// [`LocalAccess::state_raw`]'s default falls back to doing exactly that. In other words,
+    /// calling `state` on an untouched plain thread local will itself run its initializer and
+    /// then report [`LocalState::Valid`] - [`LocalState::Uninitialized`] is unreachable for that
+    /// backend. Only a [`LocalAccess`] implementation that tracks its own construction state can
+    /// report `Uninitialized` without the side effect; see [`LocalAccess::state_raw`].
+    pub fn state<T>(self, target: impl LocalAccess<T>) -> LocalState {
+        target.state_raw()
+    }
+
+    /// Like [`access`](Self::access), but returns `None` instead of constructing the value when
+    /// it isn't already [`LocalState::Valid`].
+    ///
+    /// This is useful for an error-handling fallback (don't pay to build a value you might
+    /// immediately discard) and for letting a destructor probe a sibling local safely, without
+    /// itself triggering that sibling's lazy construction - **provided the backend can actually
+    /// tell `Uninitialized` apart from `Valid`**. For the bundled `&'static LocalKey<T>` backend
+    /// it can't (see [`state`](Self::state)), so on an untouched plain thread local this still
+    /// constructs the value and returns `Some`, exactly like `access` would. The "avoid building
+    /// a value you might discard" guarantee only holds for a [`LocalAccess`] backend that
+    /// overrides [`state_raw`](LocalAccess::state_raw) to track construction itself.
+    pub fn access_if_present<T>(self, target: impl LocalAccess<T>) -> Option<&'a T> {
+        match target.state_raw() {
+            LocalState::Valid => self.try_access(target).ok(),
+            LocalState::Uninitialized | LocalState::Destroyed => None,
+        }
+    }
 }
 
 #[cfg_attr(not(panic = "immediate-abort"), inline(never))]
@@ -129,6 +162,346 @@ fn panic_access_error(err: AccessError) -> ! {
     panic!("cannot access a Thread Local Storage value during or after destruction: {err:?}")
 }
 
+/// Backend that [`LocalScope::access`]/[`try_access`](LocalScope::try_access) can reach into.
+///
+/// The default, and only backend in std, is `&'static LocalKey<T>` itself. Implementing this
+/// trait for your own type lets [`LocalScope`]'s `'a`-guaranteed, closure-free accessor surface
+/// plug into other TLS implementations - the `#[thread_local]` attribute, the `thread_local`
+/// crate, a `once_cell`-backed local - without giving up the behavior std's `LocalKey` already
+/// gets.
+///
+/// # Safety
+///
+/// [`LocalScope::access`]/[`try_access`](LocalScope::try_access) unconditionally promote the
+/// reference [`try_access_raw`](Self::try_access_raw) returns to `&'a T` via an unsafe lifetime
+/// extension, trusting the implementation rather than the type system to have made that sound.
+/// Implementing this trait is an assertion that the returned reference is backed by storage
+/// that genuinely lives for as long as the current thread's TLS region does - the same
+/// storage guarantee `std::thread::LocalKey` itself provides - and in particular is not backed
+/// by a local, a temporary, or anything else that can be dropped while a [`LocalScope`] that
+/// could still read it is alive.
+pub unsafe trait LocalAccess<T> {
+    /// Returns a reference to the underlying value, or an error if it's unavailable (for
+    /// example because it's already been destroyed on this thread).
+    ///
+    /// # Safety
+    ///
+    /// The returned reference must be backed by storage that lives at least as long as the
+    /// current thread's TLS region - see the trait's safety section. Callers ([`LocalScope`])
+    /// rely on this to extend the reference to `'a` themselves; the reference does not need to
+    /// already be valid for `'a` on its own, only for the implementation to guarantee its
+    /// backing storage will not go away before TLS teardown.
+    ///
+    /// [`AccessError`] has no public constructor, so a backend that can genuinely never fail
+    /// may simply never return `Err`; one that can should obtain its `AccessError` from an
+    /// underlying `LocalKey::try_with` call rather than trying to manufacture one.
+    unsafe fn try_access_raw(&self) -> Result<&T, AccessError>;
+
+    /// Reports the state of the underlying storage, ideally without constructing the value.
+    ///
+    /// The default implementation falls back to [`try_access_raw`](Self::try_access_raw),
+    /// mapping `Ok` to [`LocalState::Valid`] and `Err` to [`LocalState::Destroyed`] - which is
+    /// as much as stable `std::thread::LocalKey` can tell us, since it has no public way to ask
+    /// whether a value has been constructed without also constructing it. A backend that
+    /// tracks its own construction state (for example one built on a `RefCell<Option<T>>`
+    /// rather than `LocalKey`'s own initializer) can override this to report
+    /// [`LocalState::Uninitialized`] precisely, without the side effect.
+    fn state_raw(&self) -> LocalState {
+        // safety: we only inspect whether construction succeeded, we don't hand the reference
+        // back to a caller, so the backing-storage invariant this call relies on doesn't need
+        // to outlive this function body.
+        match unsafe { self.try_access_raw() } {
+            Ok(_) => LocalState::Valid,
+            Err(_) => LocalState::Destroyed,
+        }
+    }
+}
+
+// safety: a `&'static LocalKey<T>`'s value lives in genuine OS/runtime TLS storage for the
+// current thread, which by definition lives at least as long as that thread's TLS region.
+unsafe impl<T: 'static> LocalAccess<T> for &'static LocalKey<T> {
+    unsafe fn try_access_raw(&self) -> Result<&T, AccessError> {
+        self.try_with(
+            #[inline]
+            |tls| {
+                // safety: tls is a reference to data that lives in a TLS slot for the current
+                // thread; std guarantees that slot outlives this `try_with` call.
+                unsafe { &*(tls as *const T) }
+            },
+        )
+    }
+}
+
+/// The state of a thread local storage slot, as reported by [`LocalScope::state`].
+///
+/// Modeled on the `LocalKeyState` std once exposed directly on `LocalKey` before removing it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LocalState {
+    /// The value has not been constructed on this thread yet.
+    Uninitialized,
+    /// The value is constructed and safe to access.
+    Valid,
+    /// The value's destructor has already run, or is currently running, on this thread.
+    Destroyed,
+}
+
+/// Typed helpers for thread locals backed by a [`Cell`], so callers don't have to reach through
+/// [`access`](LocalScope::access) to poke at the cell themselves.
+///
+/// Generic over [`LocalAccess`] rather than hard-coded to `&'static LocalKey<Cell<T>>`, so a
+/// custom backend gets the same ergonomics as the bundled std one.
+impl<'a> LocalScope<'a> {
+    /// Equivalent to [`Cell::get`] without the need for a `with` call.
+    pub fn get<T: Copy>(self, target: impl LocalAccess<Cell<T>>) -> T {
+        self.access(target).get()
+    }
+
+    /// Equivalent to [`Cell::set`] without the need for a `with` call.
+    pub fn set<T>(self, target: impl LocalAccess<Cell<T>>, value: T) {
+        self.access(target).set(value)
+    }
+
+    /// Equivalent to [`Cell::replace`] without the need for a `with` call.
+    pub fn replace<T>(self, target: impl LocalAccess<Cell<T>>, value: T) -> T {
+        self.access(target).replace(value)
+    }
+
+    /// Equivalent to [`Cell::take`] without the need for a `with` call.
+    pub fn take<T: Default>(self, target: impl LocalAccess<Cell<T>>) -> T {
+        self.access(target).take()
+    }
+}
+
+/// Typed helpers for thread locals backed by a [`RefCell`], so callers don't have to reach
+/// through [`access`](LocalScope::access) to poke at the cell themselves.
+///
+/// Because [`access`](LocalScope::access) already hands back a `&'a RefCell<T>`, the `Ref`/`RefMut`
+/// guards returned here carry the same `'a`, so a borrow can be held across several statements
+/// without nesting `with` calls. Generic over [`LocalAccess`] for the same reason as the
+/// `Cell` helpers above.
+impl<'a> LocalScope<'a> {
+    /// Equivalent to [`RefCell::borrow`] without the need for a `with` call.
+    pub fn borrow<T>(self, target: impl LocalAccess<RefCell<T>>) -> Ref<'a, T> {
+        self.access(target).borrow()
+    }
+
+    /// Equivalent to [`RefCell::borrow_mut`] without the need for a `with` call.
+    pub fn borrow_mut<T>(self, target: impl LocalAccess<RefCell<T>>) -> RefMut<'a, T> {
+        self.access(target).borrow_mut()
+    }
+
+    /// Runs `f` against an immutable borrow of the `RefCell`'s contents.
+    pub fn with_borrow<T, R>(self, target: impl LocalAccess<RefCell<T>>, f: impl FnOnce(&T) -> R) -> R {
+        f(&self.borrow(target))
+    }
+
+    /// Runs `f` against a mutable borrow of the `RefCell`'s contents.
+    pub fn with_borrow_mut<T, R>(
+        self,
+        target: impl LocalAccess<RefCell<T>>,
+        f: impl FnOnce(&mut T) -> R,
+    ) -> R {
+        f(&mut self.borrow_mut(target))
+    }
+}
+
+/// Declares one or more [`ScopedLocalKey`]s, the scoped counterpart to `thread_local!`.
+///
+/// Unlike an ordinary `thread_local!` static, a `scoped_local!` key owns no value and has no
+/// initializer: it starts out unset, and a caller temporarily installs a *borrowed* value for a
+/// dynamic extent with [`LocalScope::bind`]. This lets non-`'static` data (a request id
+/// borrowed from the stack, say) flow through thread-local storage as ambient context, the same
+/// way [RFC 909](https://github.com/rust-lang/rfcs/blob/master/text/0909-move-out-of-thread-local.md)-style
+/// scoped thread locals do.
+///
+/// ```
+/// # use thread_local_scope::{local_scope, scoped_local};
+/// scoped_local! {
+///     static REQUEST_ID: u64;
+/// }
+///
+/// fn current_request_id() -> u64 {
+///     local_scope(|s| s.try_read(&REQUEST_ID, |id| *id).unwrap_or(0))
+/// }
+///
+/// let id = 7;
+/// local_scope(|s| {
+///     let _guard = s.bind(&REQUEST_ID, &id);
+///     assert_eq!(current_request_id(), 7);
+/// });
+/// assert_eq!(current_request_id(), 0);
+/// ```
+#[macro_export]
+macro_rules! scoped_local {
+    () => {};
+
+    ($(#[$attr:meta])* $vis:vis static $name:ident: $t:ty; $($rest:tt)*) => {
+        $(#[$attr])*
+        $vis static $name: $crate::ScopedLocalKey<$t> = {
+            ::std::thread_local! {
+                static SLOT: ::std::cell::Cell<*const ()> = const { ::std::cell::Cell::new(::std::ptr::null()) };
+            }
+            $crate::ScopedLocalKey::__new(&SLOT)
+        };
+        $crate::scoped_local! { $($rest)* }
+    };
+}
+
+/// Key for a value temporarily installed into thread-local storage by [`LocalScope::bind`].
+///
+/// Created by [`scoped_local!`]; see that macro for an example. Each key is backed by its own
+/// `thread_local! { static SLOT: Cell<*const ()> = .. }`, so two `ScopedLocalKey<T>`s never share
+/// storage even when `T` is the same type.
+///
+/// A `ScopedLocalKey` holds no accessors of its own; like every other key type in this crate, it's
+/// read and written through [`LocalScope`] (see [`bind`](LocalScope::bind),
+/// [`read`](LocalScope::read), [`try_read`](LocalScope::try_read),
+/// [`is_set`](LocalScope::is_set)), so `s.bind(&KEY, &value)` reads the same way `s.access(&KEY)`
+/// does for a plain `thread_local!`.
+pub struct ScopedLocalKey<T> {
+    slot: &'static LocalKey<Cell<*const ()>>,
+    // fn() -> T rather than T so the key itself is Send + Sync, and so usable in a `static`,
+    // whatever T is (even a !Sync T such as a `Cell`); we never actually store a T, only an
+    // erased pointer to one.
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T> ScopedLocalKey<T> {
+    #[doc(hidden)]
+    pub const fn __new(slot: &'static LocalKey<Cell<*const ()>>) -> Self {
+        Self {
+            slot,
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// Accessors for [`ScopedLocalKey`], the scoped-TLS counterpart to the [`Cell`]/[`RefCell`]
+/// helpers above.
+///
+/// These would naturally be called `set`/`get` to mirror [`Cell::set`]/[`Cell::get`], but those
+/// names are already taken by the `Cell` accessors on `LocalScope`, so the install/read
+/// operations below are named `bind`/`read` instead.
+impl<'a> LocalScope<'a> {
+    /// Installs `value` for as long as the returned [`SetGuard`] stays alive, saving whatever
+    /// was previously installed and restoring it on drop - including when the guard is dropped
+    /// by an unwinding panic - so nested `bind` calls behave like a stack.
+    pub fn bind<'b, T>(self, key: &'static ScopedLocalKey<T>, value: &'b T) -> SetGuard<'b> {
+        let ptr = value as *const T as *const ();
+        let previous = key.slot.with(|cell| cell.replace(ptr));
+        SetGuard {
+            slot: key.slot,
+            installed: ptr,
+            previous,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns whether a value is currently installed for `key` on the current thread.
+    pub fn is_set<T>(self, key: &'static ScopedLocalKey<T>) -> bool {
+        !key.slot.with(Cell::get).is_null()
+    }
+
+    /// Runs `f` against the currently installed value, panicking if nothing is installed.
+    pub fn read<T, R>(self, key: &'static ScopedLocalKey<T>, f: impl FnOnce(&T) -> R) -> R {
+        match self.try_read(key, f) {
+            Ok(r) => r,
+            Err(err) => panic_not_set(err),
+        }
+    }
+
+    /// Fallible version of [`read`](Self::read) that returns [`NotSetError`] instead of panicking
+    /// when nothing is installed.
+    ///
+    /// `f` receives a plain `&T` rather than this returning a reference directly, because the
+    /// installed value's real lifetime is the dynamic extent of the innermost active
+    /// [`SetGuard`] - which isn't nameable as a Rust lifetime at the call site. Bounding the
+    /// reference to `f`'s call keeps this sound without requiring a nested `local_scope`.
+    pub fn try_read<T, R>(
+        self,
+        key: &'static ScopedLocalKey<T>,
+        f: impl FnOnce(&T) -> R,
+    ) -> Result<R, NotSetError> {
+        let ptr = key.slot.with(Cell::get);
+        if ptr.is_null() {
+            return Err(NotSetError(()));
+        }
+        // safety: a non-null pointer is only ever stored by `bind`, for the dynamic extent of
+        // the `SetGuard` it returns. We just observed it still installed, so that guard hasn't
+        // restored the previous pointer yet, meaning `value` is still alive for this call.
+        Ok(f(unsafe { &*(ptr as *const T) }))
+    }
+}
+
+/// RAII guard returned by [`LocalScope::bind`].
+///
+/// While this guard is alive, the value it installed is visible through
+/// [`LocalScope::read`]/[`try_read`](LocalScope::try_read) on this thread. Dropping it -
+/// including via an unwinding panic - restores whatever was installed before, *provided* the slot
+/// still holds the pointer this guard installed.
+///
+/// Guards only nest safely in LIFO order, just like `RefCell` borrows. If a guard is kept alive
+/// past the drop of a guard installed before it - e.g. by moving it out of the block that created
+/// it - dropping it can no longer tell what the "right" previous value is, and restoring
+/// unconditionally would silently hand out a dangling reference to whatever called
+/// [`LocalScope::read`] next. So each guard instead remembers the exact pointer it installed
+/// and only restores `previous` if the slot still holds that pointer; otherwise it panics rather
+/// than corrupt the stack.
+pub struct SetGuard<'b> {
+    slot: &'static LocalKey<Cell<*const ()>>,
+    installed: *const (),
+    previous: *const (),
+    _marker: PhantomData<&'b ()>,
+}
+
+impl<'b> fmt::Debug for SetGuard<'b> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SetGuard").finish_non_exhaustive()
+    }
+}
+
+impl<'b> Drop for SetGuard<'b> {
+    fn drop(&mut self) {
+        self.slot.with(|cell| {
+            if cell.get() == self.installed {
+                cell.set(self.previous);
+            } else {
+                panic_out_of_order_drop();
+            }
+        });
+    }
+}
+
+/// Panics because a [`SetGuard`] was dropped while a guard installed after it was still alive.
+#[cfg_attr(not(panic = "immediate-abort"), inline(never))]
+#[track_caller]
+#[cold]
+fn panic_out_of_order_drop() -> ! {
+    panic!(
+        "SetGuard dropped out of order: a guard for the same scoped local key, installed after \
+         this one, is still alive, so restoring the previous value here would corrupt the stack"
+    )
+}
+
+/// Error returned by [`LocalScope::try_read`] when no value is currently installed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NotSetError(());
+
+impl fmt::Display for NotSetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("scoped local key has no value installed on this thread")
+    }
+}
+
+impl std::error::Error for NotSetError {}
+
+#[cfg_attr(not(panic = "immediate-abort"), inline(never))]
+#[track_caller]
+#[cold]
+fn panic_not_set(err: NotSetError) -> ! {
+    panic!("cannot read scoped local key: {err}")
+}
+
 #[cfg(test)]
 mod test {
     use crate::*;
@@ -187,4 +560,263 @@ mod test {
         assert_eq!(A.get(), 1);
         assert_eq!(B.get(), 0);
     }
+
+    #[test]
+    fn cell_accessors() {
+        thread_local! {
+            static COUNT: Cell<u32> = Cell::new(0);
+        }
+
+        local_scope(|s| {
+            assert_eq!(s.get(&COUNT), 0);
+            s.set(&COUNT, 41);
+            assert_eq!(s.replace(&COUNT, 42), 41);
+            assert_eq!(s.take(&COUNT), 42);
+            assert_eq!(s.get(&COUNT), 0);
+        });
+    }
+
+    #[test]
+    fn refcell_accessors() {
+        use std::cell::RefCell;
+
+        thread_local! {
+            static LOG: RefCell<Vec<&'static str>> = RefCell::new(Vec::new());
+        }
+
+        local_scope(|s| {
+            s.borrow_mut(&LOG).push("a");
+            s.with_borrow_mut(&LOG, |log| log.push("b"));
+            assert_eq!(*s.borrow(&LOG), vec!["a", "b"]);
+            assert_eq!(s.with_borrow(&LOG, |log| log.len()), 2);
+        });
+    }
+
+    #[test]
+    fn scoped_local_nests_like_a_stack() {
+        scoped_local! {
+            static CURRENT: u32;
+        }
+
+        local_scope(|s| {
+            assert!(!s.is_set(&CURRENT));
+            assert!(s.try_read(&CURRENT, |_| ()).is_err());
+
+            let outer = 1;
+            let _outer_guard = s.bind(&CURRENT, &outer);
+            assert_eq!(s.read(&CURRENT, |x| *x), 1);
+
+            {
+                let inner = 2;
+                let _inner_guard = s.bind(&CURRENT, &inner);
+                assert_eq!(s.read(&CURRENT, |x| *x), 2);
+            }
+
+            assert_eq!(s.read(&CURRENT, |x| *x), 1);
+        });
+
+        assert!(local_scope(|s| s.try_read(&CURRENT, |_| ()).is_err()));
+    }
+
+    #[test]
+    #[should_panic(expected = "dropped out of order")]
+    fn scoped_local_out_of_order_drop_panics() {
+        scoped_local! {
+            static CURRENT: u32;
+        }
+
+        local_scope(|s| {
+            let b: u32 = 2;
+            let gb;
+            {
+                let a: u32 = 1;
+                let _ga = s.bind(&CURRENT, &a);
+                gb = s.bind(&CURRENT, &b);
+            }
+            // `_ga` was dropped at the end of the block above, out of LIFO order relative to
+            // `gb`, which is still alive: that drop already panics, so this line is never
+            // reached. Without the installed-pointer check `_ga` would have reset the slot, and
+            // dropping `gb` here would silently restore it to a dangling pointer to `a`.
+            drop(gb);
+        });
+    }
+
+    #[test]
+    fn scoped_local_restores_on_panic() {
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+
+        scoped_local! {
+            static CURRENT: u32;
+        }
+
+        local_scope(|s| {
+            let outer = 1;
+            let _outer_guard = s.bind(&CURRENT, &outer);
+
+            let result = catch_unwind(AssertUnwindSafe(|| {
+                local_scope(|s| {
+                    let inner = 2;
+                    let _inner_guard = s.bind(&CURRENT, &inner);
+                    panic!("boom");
+                })
+            }));
+            assert!(result.is_err());
+
+            assert_eq!(s.read(&CURRENT, |x| *x), 1);
+        });
+    }
+
+    #[test]
+    fn custom_local_access_backend() {
+        // A minimal non-std backend: adapts an existing `LocalKey` without exposing it as one,
+        // proving `access`/`try_access` work against any `LocalAccess` impl, not just the
+        // built-in `&'static LocalKey<T>` one.
+        #[derive(Clone, Copy)]
+        struct Adapted(&'static LocalKey<Cell<u8>>);
+
+        // safety: forwards to a `&'static LocalKey<Cell<u8>>`, which already upholds the
+        // required storage guarantee.
+        unsafe impl LocalAccess<Cell<u8>> for Adapted {
+            unsafe fn try_access_raw(&self) -> Result<&Cell<u8>, AccessError> {
+                unsafe { self.0.try_access_raw() }
+            }
+        }
+
+        thread_local! {
+            static COUNT: Cell<u8> = Cell::new(0);
+        }
+
+        let key = Adapted(&COUNT);
+
+        local_scope(|s| {
+            s.access(key).set(5);
+            assert_eq!(s.access(key).get(), 5);
+
+            // the Cell ergonomics from `get`/`set`/`replace`/`take` plug into any `LocalAccess`
+            // backend, not just `&'static LocalKey<Cell<T>>`.
+            assert_eq!(s.replace(key, 6), 5);
+            assert_eq!(s.get(key), 6);
+            s.set(key, 7);
+            assert_eq!(s.take(key), 7);
+        });
+    }
+
+    #[test]
+    fn state_reports_destroyed() {
+        thread_local! {
+            static MY_THING: MyThing = MyThing;
+        }
+
+        struct MyThing;
+        impl Drop for MyThing {
+            fn drop(&mut self) {
+                local_scope(|sc| {
+                    assert_eq!(sc.state(&MY_THING), LocalState::Destroyed);
+                    assert!(sc.access_if_present(&MY_THING).is_none());
+                })
+            }
+        }
+
+        spawn(|| {
+            local_scope(|s| assert_eq!(s.state(&MY_THING), LocalState::Valid))
+        })
+        .join()
+        .unwrap();
+    }
+
+    #[test]
+    fn plain_local_key_cannot_avoid_construction() {
+        // The bundled `&'static LocalKey<T>` backend has no way to tell "uninitialized" apart
+        // from "valid" without forcing construction - `state`/`access_if_present` can only
+        // fall back to doing exactly that, defeating their own construction-avoidance promise.
+        thread_local! {
+            static STATE_BUILDS: Cell<u32> = Cell::new(0);
+            static FOR_STATE: Cell<u32> = {
+                STATE_BUILDS.with(|b| b.set(b.get() + 1));
+                Cell::new(0)
+            };
+
+            static ACCESS_IF_PRESENT_BUILDS: Cell<u32> = Cell::new(0);
+            static FOR_ACCESS_IF_PRESENT: Cell<u32> = {
+                ACCESS_IF_PRESENT_BUILDS.with(|b| b.set(b.get() + 1));
+                Cell::new(0)
+            };
+        }
+
+        local_scope(|s| {
+            assert_eq!(STATE_BUILDS.with(Cell::get), 0, "not touched yet");
+            assert_eq!(s.state(&FOR_STATE), LocalState::Valid);
+            assert_eq!(STATE_BUILDS.with(Cell::get), 1, "`state` forced construction");
+
+            assert_eq!(
+                ACCESS_IF_PRESENT_BUILDS.with(Cell::get),
+                0,
+                "not touched yet"
+            );
+            assert!(s.access_if_present(&FOR_ACCESS_IF_PRESENT).is_some());
+            assert_eq!(
+                ACCESS_IF_PRESENT_BUILDS.with(Cell::get),
+                1,
+                "`access_if_present` forced construction"
+            );
+        });
+    }
+
+    #[test]
+    fn access_if_present_avoids_construction() {
+        // A backend that tracks its own construction state can report `Uninitialized`
+        // precisely, unlike the bundled `&'static LocalKey<T>` one.
+        #[derive(Clone, Copy)]
+        struct Lazy<T: 'static> {
+            slot: &'static LocalKey<RefCell<Option<T>>>,
+            init: fn() -> T,
+        }
+
+        // safety: the `Option<T>` lives in genuine TLS storage for the current thread (it's
+        // itself a `thread_local!`), and once filled is never emptied or moved out from under
+        // an existing borrow, so a reference into it lives as long as the thread's TLS region.
+        unsafe impl<T: 'static> LocalAccess<T> for Lazy<T> {
+            unsafe fn try_access_raw(&self) -> Result<&T, AccessError> {
+                self.slot.with(|cell| {
+                    if cell.borrow().is_none() {
+                        *cell.borrow_mut() = Some((self.init)());
+                    }
+                    Ok(unsafe { &*(cell.borrow().as_ref().unwrap() as *const T) })
+                })
+            }
+
+            fn state_raw(&self) -> LocalState {
+                self.slot.with(|cell| match &*cell.borrow() {
+                    Some(_) => LocalState::Valid,
+                    None => LocalState::Uninitialized,
+                })
+            }
+        }
+
+        thread_local! {
+            static BUILT: Cell<u32> = Cell::new(0);
+            static SLOT: RefCell<Option<u32>> = RefCell::new(None);
+        }
+
+        let key = Lazy {
+            slot: &SLOT,
+            init: || {
+                BUILT.with(|b| b.set(b.get() + 1));
+                7
+            },
+        };
+
+        local_scope(|s| {
+            assert_eq!(s.state(key), LocalState::Uninitialized);
+            assert!(s.access_if_present(key).is_none());
+            assert_eq!(BUILT.with(Cell::get), 0);
+
+            assert_eq!(*s.access(key), 7);
+            assert_eq!(BUILT.with(Cell::get), 1);
+
+            assert_eq!(s.state(key), LocalState::Valid);
+            assert_eq!(*s.access_if_present(key).unwrap(), 7);
+            assert_eq!(BUILT.with(Cell::get), 1);
+        });
+    }
 }